@@ -1,12 +1,17 @@
+use std::collections::VecDeque;
+
 use bevy::{
-    ecs::query::QueryFilter,
+    ecs::system::SystemState,
+    gizmos::config::{DefaultGizmoConfigGroup, GizmoConfigStore},
+    math::Affine3A,
     prelude::*,
     render::{camera::CameraProjection, view::RenderLayers},
 };
 
 use bevy_editor_pls_core::editor_window::{EditorWindow, EditorWindowContext};
+use bevy_egui::EguiContexts;
 use bevy_inspector_egui::egui;
-use transform_gizmo_egui::{GizmoExt, GizmoMode};
+use transform_gizmo_egui::{GizmoExt, GizmoMode, GizmoOrientation};
 
 use crate::{
     cameras::{ActiveEditorCamera, EditorCamera, EDITOR_RENDER_LAYER},
@@ -16,7 +21,15 @@ use crate::{
 pub struct GizmoState {
     pub camera_gizmo_active: bool,
     pub gizmo_mode: transform_gizmo_egui::EnumSet<transform_gizmo_egui::GizmoMode>,
+    pub gizmo_orientation: GizmoOrientation,
+    pub snapping: bool,
+    pub snap_angle: f32,
+    pub snap_distance: f32,
+    pub snap_scale: f32,
     pub gizmo: transform_gizmo_egui::Gizmo,
+    pub light_gizmo_visibility: LightGizmoVisibility,
+    pub marker_style: GizmoMarkerStyle,
+    pub pivot_mode: PivotMode,
 }
 
 impl Default for GizmoState {
@@ -25,6 +38,32 @@ impl Default for GizmoState {
             camera_gizmo_active: true,
             gizmo: transform_gizmo_egui::Gizmo::default(),
             gizmo_mode: transform_gizmo_egui::EnumSet::only(GizmoMode::Translate),
+            gizmo_orientation: GizmoOrientation::Local,
+            snapping: false,
+            snap_angle: 45f32.to_radians(),
+            snap_distance: 1.0,
+            snap_scale: 0.1,
+            light_gizmo_visibility: LightGizmoVisibility::default(),
+            marker_style: GizmoMarkerStyle::default(),
+            pivot_mode: PivotMode::default(),
+        }
+    }
+}
+
+/// Per light-type toggles for the shape gizmos drawn by [`draw_light_gizmos`].
+#[derive(Clone, Copy)]
+pub struct LightGizmoVisibility {
+    pub point_light: bool,
+    pub spot_light: bool,
+    pub directional_light: bool,
+}
+
+impl Default for LightGizmoVisibility {
+    fn default() -> Self {
+        Self {
+            point_light: true,
+            spot_light: true,
+            directional_light: true,
         }
     }
 }
@@ -36,34 +75,216 @@ impl EditorWindow for GizmoWindow {
 
     const NAME: &'static str = "Gizmos";
 
-    fn ui(_world: &mut World, _cx: EditorWindowContext, ui: &mut egui::Ui) {
-        ui.label("Gizmos can currently not be configured");
+    fn ui(_world: &mut World, mut cx: EditorWindowContext, ui: &mut egui::Ui) {
+        let state = cx.state_mut::<GizmoWindow>().unwrap();
+
+        ui.label("Gizmo mode");
+        ui.horizontal(|ui| {
+            for (label, mode) in [
+                ("Translate", GizmoMode::Translate),
+                ("Rotate", GizmoMode::Rotate),
+                ("Scale", GizmoMode::Scale),
+            ] {
+                let mut active = state.gizmo_mode.contains(mode);
+                if ui.checkbox(&mut active, label).changed() {
+                    if active {
+                        state.gizmo_mode.insert(mode);
+                    } else if state.gizmo_mode.len() > 1 {
+                        // Refuse to uncheck the last remaining mode so the
+                        // gizmo is never left with an empty mode set.
+                        state.gizmo_mode.remove(mode);
+                    }
+                }
+            }
+        });
+
+        ui.label("Orientation");
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut state.gizmo_orientation, GizmoOrientation::Local, "Local");
+            ui.selectable_value(
+                &mut state.gizmo_orientation,
+                GizmoOrientation::Global,
+                "Global",
+            );
+        });
+
+        ui.separator();
+
+        ui.checkbox(&mut state.snapping, "Enable snapping (hold Ctrl)");
+        ui.add_enabled_ui(state.snapping, |ui| {
+            egui::Grid::new("gizmo_snap_grid").show(ui, |ui| {
+                ui.label("Angle");
+                let mut snap_angle_degrees = state.snap_angle.to_degrees();
+                if ui
+                    .add(egui::DragValue::new(&mut snap_angle_degrees).suffix("°"))
+                    .changed()
+                {
+                    state.snap_angle = snap_angle_degrees.to_radians();
+                }
+                ui.end_row();
+
+                ui.label("Distance");
+                ui.add(egui::DragValue::new(&mut state.snap_distance).speed(0.1));
+                ui.end_row();
+
+                ui.label("Scale");
+                ui.add(egui::DragValue::new(&mut state.snap_scale).speed(0.01));
+                ui.end_row();
+            });
+        });
+
+        ui.separator();
+
+        ui.label("Pivot point (multi-selection)");
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut state.pivot_mode, PivotMode::MedianPoint, "Median Point");
+            ui.selectable_value(
+                &mut state.pivot_mode,
+                PivotMode::ActiveElement,
+                "Active Element",
+            );
+            ui.selectable_value(
+                &mut state.pivot_mode,
+                PivotMode::IndividualOrigins,
+                "Individual Origins",
+            );
+        });
+
+        ui.separator();
+
+        ui.label("Marker style");
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut state.marker_style, GizmoMarkerStyle::Billboard, "Billboard");
+            ui.selectable_value(&mut state.marker_style, GizmoMarkerStyle::Mesh, "Mesh");
+        });
+
+        ui.separator();
+
+        ui.label("Light gizmos");
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut state.light_gizmo_visibility.point_light, "Point");
+            ui.checkbox(&mut state.light_gizmo_visibility.spot_light, "Spot");
+            ui.checkbox(
+                &mut state.light_gizmo_visibility.directional_light,
+                "Directional",
+            );
+        });
     }
 
     fn viewport_toolbar_ui(world: &mut World, mut cx: EditorWindowContext, ui: &mut egui::Ui) {
+        let light_gizmo_visibility = cx.state::<GizmoWindow>().unwrap().light_gizmo_visibility;
+        world.resource_mut::<LightGizmoConfig>().0 = light_gizmo_visibility;
+        let marker_style = cx.state::<GizmoWindow>().unwrap().marker_style;
+        let mut marker_style_config = world.resource_mut::<MarkerStyleConfig>();
+        if marker_style_config.0 != marker_style {
+            marker_style_config.0 = marker_style;
+        }
+
+        let selected_entities = cx
+            .state::<HierarchyWindow>()
+            .unwrap()
+            .selected
+            .iter()
+            .collect::<Vec<_>>();
+        // `HierarchyWindow::selected`'s container has no documented ordering
+        // guarantee, so `.last()` over it can't be trusted to mean "most
+        // recently selected". Track that explicitly here instead.
+        let active_entity = world
+            .resource_mut::<ActiveSelectionTracker>()
+            .update(&selected_entities);
+        world.resource_mut::<SelectedCameraHighlight>().0 = active_entity;
+
         let (camera_gizmo_active, gizmo_mode) = {
+            let state = cx.state_mut::<GizmoWindow>().unwrap();
+
+            ui.horizontal(|ui| {
+                for (label, mode) in [
+                    ("Translate", GizmoMode::Translate),
+                    ("Rotate", GizmoMode::Rotate),
+                    ("Scale", GizmoMode::Scale),
+                ] {
+                    let mut active = state.gizmo_mode.contains(mode);
+                    if ui.selectable_label(active, label).clicked() {
+                        active = !active;
+                        if active {
+                            state.gizmo_mode.insert(mode);
+                        } else if state.gizmo_mode.len() > 1 {
+                            // Refuse to uncheck the last remaining mode so
+                            // the gizmo is never left with an empty mode set.
+                            state.gizmo_mode.remove(mode);
+                        }
+                    }
+                }
+
+                ui.separator();
+
+                let orientation_label = match state.gizmo_orientation {
+                    GizmoOrientation::Local => "Local",
+                    GizmoOrientation::Global => "Global",
+                };
+                if ui.button(orientation_label).clicked() {
+                    state.gizmo_orientation = match state.gizmo_orientation {
+                        GizmoOrientation::Local => GizmoOrientation::Global,
+                        GizmoOrientation::Global => GizmoOrientation::Local,
+                    };
+                }
+
+                ui.separator();
+
+                if ui.selectable_label(state.snapping, "Snap").clicked() {
+                    state.snapping = !state.snapping;
+                }
+            });
+
             let GizmoState {
                 camera_gizmo_active,
                 gizmo_mode,
                 ..
-            } = cx.state::<GizmoWindow>().unwrap();
+            } = state;
             (*camera_gizmo_active, *gizmo_mode)
         };
 
         if camera_gizmo_active {
-            if let Some(new_config) = collect_gizmo_config(ui, world, gizmo_mode) {
+            let (orientation, snapping, snap_angle, snap_distance, snap_scale, pivot_mode) = {
+                let state = cx.state::<GizmoWindow>().unwrap();
+                (
+                    state.gizmo_orientation,
+                    state.snapping,
+                    state.snap_angle,
+                    state.snap_distance,
+                    state.snap_scale,
+                    state.pivot_mode,
+                )
+            };
+
+            // Holding Ctrl force-enables snapping for the duration of the drag,
+            // mirroring the convention used by Blender and other DCC tools.
+            let snapping = snapping || ui.input(|input| input.modifiers.ctrl);
+
+            if let Some(new_config) = collect_gizmo_config(
+                ui,
+                world,
+                gizmo_mode,
+                orientation,
+                snapping,
+                snap_angle,
+                snap_distance,
+                snap_scale,
+            ) {
                 cx.state_mut::<GizmoWindow>()
                     .unwrap()
                     .gizmo
                     .update_config(new_config);
             }
-            let selected_entities = cx
-                .state::<HierarchyWindow>()
-                .unwrap()
-                .selected.iter().collect::<Vec<_>>();
-
             let gizmo_state_mut = cx.state_mut::<GizmoWindow>().unwrap();
-            draw_gizmo(ui, world, &selected_entities, &mut gizmo_state_mut.gizmo);
+            draw_gizmo(
+                ui,
+                world,
+                &selected_entities,
+                &mut gizmo_state_mut.gizmo,
+                pivot_mode,
+                active_entity,
+            );
         }
     }
 
@@ -86,100 +307,653 @@ impl EditorWindow for GizmoWindow {
 
         let mut meshes = app.world.resource_mut::<Assets<Mesh>>();
         let sphere = meshes.add(Sphere { radius: 0.3 });
+        let billboard_quad = meshes.add(Rectangle::from_size(Vec2::splat(0.3)));
+
+        let asset_server = app.world.resource::<AssetServer>();
+        let point_light_icon = asset_server.load("icons/point_light.png");
+        let directional_light_icon = asset_server.load("icons/directional_light.png");
+        let spot_light_icon = asset_server.load("icons/spot_light.png");
+        let camera_icon = asset_server.load("icons/camera.png");
+
+        let mut icon_materials = app.world.resource_mut::<Assets<StandardMaterial>>();
+        let point_light_icon_material =
+            icon_materials.add(billboard_icon_material(point_light_icon.clone()));
+        let directional_light_icon_material =
+            icon_materials.add(billboard_icon_material(directional_light_icon.clone()));
+        let spot_light_icon_material =
+            icon_materials.add(billboard_icon_material(spot_light_icon.clone()));
+        let camera_icon_material = icon_materials.add(billboard_icon_material(camera_icon.clone()));
 
         app.world.insert_resource(GizmoMarkerConfig {
             point_light_mesh: sphere.clone(),
             point_light_material: material_light.clone(),
             directional_light_mesh: sphere.clone(),
-            directional_light_material: material_light,
+            directional_light_material: material_light.clone(),
+            spot_light_mesh: sphere.clone(),
+            spot_light_material: material_light,
             camera_mesh: sphere,
             camera_material: material_camera,
+
+            billboard_quad,
+            point_light_icon,
+            directional_light_icon,
+            spot_light_icon,
+            camera_icon,
+            point_light_icon_material,
+            directional_light_icon_material,
+            spot_light_icon_material,
+            camera_icon_material,
         });
 
-        app.add_systems(PostUpdate, add_gizmo_markers);
+        app.world.insert_resource(LightGizmoConfig::default());
+        app.world.insert_resource(SelectedCameraHighlight::default());
+        app.world.insert_resource(MarkerStyleConfig::default());
+        app.world.insert_resource(ActiveSelectionTracker::default());
+
+        app.add_event::<TransformGizmoEvent>();
+        app.world.insert_resource(GizmoDragTracker::default());
+        app.world.insert_resource(UndoRedoStack::default());
+        app.add_systems(Update, transform_undo_redo_input);
+
+        let mut gizmo_config_store = app.world.resource_mut::<GizmoConfigStore>();
+        let (config, _) = gizmo_config_store.config_mut::<DefaultGizmoConfigGroup>();
+        config.render_layers = RenderLayers::layer(EDITOR_RENDER_LAYER);
+
+        app.add_systems(
+            PostUpdate,
+            (
+                add_gizmo_markers,
+                restyle_gizmo_markers.after(add_gizmo_markers),
+                draw_light_gizmos,
+                draw_camera_frustums,
+                orient_billboard_gizmo_markers
+                    .after(add_gizmo_markers)
+                    .after(restyle_gizmo_markers)
+                    .before(bevy::transform::TransformSystem::TransformPropagate),
+                sync_gizmo_marker_icon_materials.before(add_gizmo_markers),
+            ),
+        );
+    }
+}
+
+/// Pushes the (possibly user-overridden) icon texture handles on
+/// [`GizmoMarkerConfig`] into the billboard materials `add_gizmo_markers` and
+/// `restyle_gizmo_markers` hand out, so replacing e.g.
+/// `GizmoMarkerConfig::point_light_icon` at runtime actually changes what's
+/// drawn instead of only affecting the unused material the icon was
+/// originally baked into.
+fn sync_gizmo_marker_icon_materials(
+    config: Res<GizmoMarkerConfig>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+
+    for (material_handle, icon) in [
+        (&config.point_light_icon_material, &config.point_light_icon),
+        (
+            &config.directional_light_icon_material,
+            &config.directional_light_icon,
+        ),
+        (&config.spot_light_icon_material, &config.spot_light_icon),
+        (&config.camera_icon_material, &config.camera_icon),
+    ] {
+        if let Some(material) = materials.get_mut(material_handle) {
+            material.base_color_texture = Some(icon.clone());
+        }
+    }
+}
+
+fn billboard_icon_material(icon: Handle<Image>) -> StandardMaterial {
+    StandardMaterial {
+        base_color_texture: Some(icon),
+        unlit: true,
+        fog_enabled: false,
+        alpha_mode: AlphaMode::Blend,
+        cull_mode: None,
+        ..default()
+    }
+}
+
+/// Tracks the entity to draw a highlighted frustum for, mirrored each frame from
+/// [`HierarchyWindow::selected`].
+#[derive(Resource, Default)]
+struct SelectedCameraHighlight(Option<Entity>);
+
+/// Derives "the most recently selected entity" from successive frames of
+/// [`HierarchyWindow::selected`], since that container makes no promise about
+/// iteration order. Diffs the current selection against the previous frame's:
+/// whichever entity is newly present is the one the user just clicked, which
+/// is what "Active Element" pivot mode and the highlighted camera frustum
+/// both actually want.
+#[derive(Resource, Default)]
+struct ActiveSelectionTracker {
+    previous: bevy::utils::HashSet<Entity>,
+    active: Option<Entity>,
+}
+
+impl ActiveSelectionTracker {
+    fn update(&mut self, current: &[Entity]) -> Option<Entity> {
+        let current_set: bevy::utils::HashSet<Entity> = current.iter().copied().collect();
+
+        self.active = match current.iter().find(|entity| !self.previous.contains(entity)) {
+            Some(&newly_selected) => Some(newly_selected),
+            None if self.active.is_some_and(|entity| current_set.contains(&entity)) => {
+                self.active
+            }
+            // Nothing new this frame and the previously active entity is no
+            // longer selected (or there wasn't one yet): fall back to
+            // whatever the container happens to report last.
+            None => current.last().copied(),
+        };
+
+        self.previous = current_set;
+        self.active
     }
 }
 
+const CAMERA_FRUSTUM_COLOR: Color = Color::rgb(0.3, 0.6, 1.0);
+const SELECTED_CAMERA_FRUSTUM_COLOR: Color = Color::rgb(1.0, 0.6, 0.1);
+const CAMERA_FRUSTUM_FAR_CLAMP: f32 = 20.0;
+
+fn draw_camera_frustums(
+    mut gizmos: Gizmos,
+    selected: Res<SelectedCameraHighlight>,
+    cameras: Query<(Entity, &GlobalTransform, &Projection), (With<Camera>, Without<EditorCamera>)>,
+) {
+    for (entity, transform, projection) in &cameras {
+        let color = if selected.0 == Some(entity) {
+            SELECTED_CAMERA_FRUSTUM_COLOR
+        } else {
+            CAMERA_FRUSTUM_COLOR
+        };
+
+        // `PerspectiveProjection::get_projection_matrix` builds an infinite-far,
+        // reverse-Z matrix that ignores `far` entirely, which would draw an
+        // unusably huge frustum; build our own finite-far matrix instead so the
+        // clamp below actually takes effect. This matrix maps z=0 to the near
+        // plane and z=1 to the far plane. Bevy's orthographic matrix is
+        // reverse-Z, so there the "near"/"far" corner names below swap; the
+        // 12 edges drawn are the same either way.
+        let projection_matrix = match projection {
+            Projection::Perspective(perspective) => Mat4::perspective_rh(
+                perspective.fov,
+                perspective.aspect_ratio,
+                perspective.near,
+                perspective.far.min(CAMERA_FRUSTUM_FAR_CLAMP),
+            ),
+            Projection::Orthographic(_) => projection.get_projection_matrix(),
+        };
+
+        let inverse_view_projection =
+            (projection_matrix * transform.compute_matrix().inverse()).inverse();
+
+        let [ntl, ntr, nbl, nbr, ftl, ftr, fbl, fbr] = [
+            Vec3::new(-1.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(-1.0, -1.0, 0.0),
+            Vec3::new(1.0, -1.0, 0.0),
+            Vec3::new(-1.0, 1.0, 1.0),
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(-1.0, -1.0, 1.0),
+            Vec3::new(1.0, -1.0, 1.0),
+        ]
+        .map(|ndc| inverse_view_projection.project_point3(ndc));
+
+        // Near plane, far plane, and the four connecting edges: 12 total.
+        for (a, b) in [
+            (ntl, ntr),
+            (ntr, nbr),
+            (nbr, nbl),
+            (nbl, ntl),
+            (ftl, ftr),
+            (ftr, fbr),
+            (fbr, fbl),
+            (fbl, ftl),
+            (ntl, ftl),
+            (ntr, ftr),
+            (nbl, fbl),
+            (nbr, fbr),
+        ] {
+            gizmos.line(a, b, color);
+        }
+    }
+}
+
+#[derive(Resource, Clone, Copy, Default)]
+struct LightGizmoConfig(LightGizmoVisibility);
+
+const LIGHT_GIZMO_COLOR: Color = Color::rgba(0.9, 0.8, 0.3, 1.0);
+const LIGHT_GIZMO_INNER_CONE_COLOR: Color = Color::rgba(0.9, 0.8, 0.3, 0.35);
+
+/// Fallback radius used when a light has no meaningful physical extent to
+/// draw at: `PointLight::radius` defaults to `0.0`, which can't be used to
+/// size a gizmo that's supposed to convey the light's visible footprint.
+const LIGHT_GIZMO_DEFAULT_RADIUS: f32 = 0.3;
+
+/// Spot-light cones are drawn `SpotLight::range` long, clamped to this
+/// length so a light left at the default (non-physical) `range` of `20.0` —
+/// or an explicitly huge one — doesn't draw an unusably long cone; mirrors
+/// how `draw_camera_frustums` clamps `far` instead of discarding it.
+const LIGHT_GIZMO_SPOT_CONE_MAX_LENGTH: f32 = 1.5;
+
+fn draw_light_gizmos(
+    mut gizmos: Gizmos,
+    config: Res<LightGizmoConfig>,
+    point_lights: Query<(&GlobalTransform, &PointLight)>,
+    spot_lights: Query<(&GlobalTransform, &SpotLight)>,
+    directional_lights: Query<&GlobalTransform, With<DirectionalLight>>,
+) {
+    let LightGizmoVisibility {
+        point_light,
+        spot_light,
+        directional_light,
+    } = config.0;
+
+    if point_light {
+        for (transform, point_light) in &point_lights {
+            let radius = if point_light.radius > 0.0 {
+                point_light.radius
+            } else {
+                LIGHT_GIZMO_DEFAULT_RADIUS
+            };
+            gizmos.sphere(transform.translation(), Quat::IDENTITY, radius, LIGHT_GIZMO_COLOR);
+        }
+    }
+
+    if spot_light {
+        for (transform, spot_light) in &spot_lights {
+            let apex = transform.translation();
+            let forward = transform.forward();
+            let cone_length = spot_light.range.min(LIGHT_GIZMO_SPOT_CONE_MAX_LENGTH);
+            draw_cone_gizmo(
+                &mut gizmos,
+                apex,
+                forward,
+                spot_light.outer_angle,
+                cone_length,
+                LIGHT_GIZMO_COLOR,
+            );
+            draw_cone_gizmo(
+                &mut gizmos,
+                apex,
+                forward,
+                spot_light.inner_angle,
+                cone_length,
+                LIGHT_GIZMO_INNER_CONE_COLOR,
+            );
+        }
+    }
+
+    if directional_light {
+        for transform in &directional_lights {
+            let origin = transform.translation();
+            let forward = transform.forward();
+            gizmos.arrow(origin, origin + forward * 1.5, LIGHT_GIZMO_COLOR);
+            gizmos.circle(origin, forward, 0.3, LIGHT_GIZMO_COLOR);
+        }
+    }
+}
+
+/// Draws a wireframe cone (apex + base circle + four side lines) pointing along `forward`.
+fn draw_cone_gizmo(
+    gizmos: &mut Gizmos,
+    apex: Vec3,
+    forward: Vec3,
+    half_angle: f32,
+    length: f32,
+    color: Color,
+) {
+    let radius = length * half_angle.tan();
+    let base_center = apex + forward * length;
+    gizmos.circle(base_center, forward, radius, color);
+
+    let (basis_a, basis_b) = forward.any_orthonormal_pair();
+    for i in 0..4 {
+        let angle = i as f32 * std::f32::consts::FRAC_PI_2;
+        let offset = (basis_a * angle.cos() + basis_b * angle.sin()) * radius;
+        gizmos.line(apex, base_center + offset, color);
+    }
+}
+
+/// Mesh/material handles for the world-space sphere markers and the texture
+/// handles for the billboard icons, keyed by object type. The mesh/material
+/// and icon texture fields are `pub` so users can override the default look
+/// by mutating this resource; the derived `*_icon_material` handles stay
+/// private and are kept in sync with the public icon textures by
+/// [`sync_gizmo_marker_icon_materials`].
 #[derive(Resource)]
-struct GizmoMarkerConfig {
-    point_light_mesh: Handle<Mesh>,
-    point_light_material: Handle<StandardMaterial>,
-    directional_light_mesh: Handle<Mesh>,
-    directional_light_material: Handle<StandardMaterial>,
-    camera_mesh: Handle<Mesh>,
-    camera_material: Handle<StandardMaterial>,
+pub struct GizmoMarkerConfig {
+    pub point_light_mesh: Handle<Mesh>,
+    pub point_light_material: Handle<StandardMaterial>,
+    pub directional_light_mesh: Handle<Mesh>,
+    pub directional_light_material: Handle<StandardMaterial>,
+    pub spot_light_mesh: Handle<Mesh>,
+    pub spot_light_material: Handle<StandardMaterial>,
+    pub camera_mesh: Handle<Mesh>,
+    pub camera_material: Handle<StandardMaterial>,
+
+    pub billboard_quad: Handle<Mesh>,
+    pub point_light_icon: Handle<Image>,
+    pub directional_light_icon: Handle<Image>,
+    pub spot_light_icon: Handle<Image>,
+    pub camera_icon: Handle<Image>,
+    point_light_icon_material: Handle<StandardMaterial>,
+    directional_light_icon_material: Handle<StandardMaterial>,
+    spot_light_icon_material: Handle<StandardMaterial>,
+    camera_icon_material: Handle<StandardMaterial>,
+}
+
+/// Whether object markers are drawn as world-space meshes or as constant-size
+/// screen-space billboards. See [`GizmoState::marker_style`].
+///
+/// Defaults to `Mesh`: the billboard icon textures loaded in `app_setup` are
+/// placeholders, not assets shipped with this crate, so `Billboard` would
+/// render broken textures out of the box until real icons are provided.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum GizmoMarkerStyle {
+    #[default]
+    Mesh,
+    Billboard,
 }
 
+/// Mirrors [`GizmoState::marker_style`] into a plain resource so the
+/// non-window [`add_gizmo_markers`] system can read it.
+#[derive(Resource, Default)]
+struct MarkerStyleConfig(GizmoMarkerStyle);
+
 #[derive(Component)]
 struct HasGizmoMarker;
 
+/// Marks a spawned marker child so [`restyle_gizmo_markers`] can find and
+/// despawn it when [`GizmoMarkerStyle`] changes, regardless of whether it was
+/// built as a mesh or a billboard.
+#[derive(Component)]
+struct GizmoMarkerChild;
+
+/// Marks a spawned marker child as a billboard that should be kept facing
+/// the active editor camera at a constant screen size by
+/// [`orient_billboard_gizmo_markers`].
+#[derive(Component)]
+struct BillboardGizmoMarker;
+
 type GizmoMarkerQuery<'w, 's, T, F = ()> =
     Query<'w, 's, Entity, (With<T>, Without<HasGizmoMarker>, F)>;
 
+/// Spawns the marker child appropriate for `billboard`, tagged with
+/// [`GizmoMarkerChild`] (and [`BillboardGizmoMarker`] when billboarded) so it
+/// can be found again by [`restyle_gizmo_markers`].
+fn spawn_gizmo_marker_child(
+    commands: &mut Commands,
+    parent: Entity,
+    name: &'static str,
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+    billboard: bool,
+) {
+    let render_layers = RenderLayers::layer(EDITOR_RENDER_LAYER);
+    commands.entity(parent).with_children(|commands| {
+        let mut marker = commands.spawn((
+            PbrBundle {
+                mesh,
+                material,
+                ..default()
+            },
+            render_layers,
+            Name::new(name),
+            GizmoMarkerChild,
+        ));
+        if billboard {
+            marker.insert(BillboardGizmoMarker);
+        }
+    });
+}
+
+fn point_light_marker_visual(
+    config: &GizmoMarkerConfig,
+    billboard: bool,
+) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+    if billboard {
+        (
+            config.billboard_quad.clone_weak(),
+            config.point_light_icon_material.clone_weak(),
+        )
+    } else {
+        (
+            config.point_light_mesh.clone_weak(),
+            config.point_light_material.clone_weak(),
+        )
+    }
+}
+
+fn directional_light_marker_visual(
+    config: &GizmoMarkerConfig,
+    billboard: bool,
+) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+    if billboard {
+        (
+            config.billboard_quad.clone_weak(),
+            config.directional_light_icon_material.clone_weak(),
+        )
+    } else {
+        (
+            config.directional_light_mesh.clone_weak(),
+            config.directional_light_material.clone_weak(),
+        )
+    }
+}
+
+fn spot_light_marker_visual(
+    config: &GizmoMarkerConfig,
+    billboard: bool,
+) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+    if billboard {
+        (
+            config.billboard_quad.clone_weak(),
+            config.spot_light_icon_material.clone_weak(),
+        )
+    } else {
+        (
+            config.spot_light_mesh.clone_weak(),
+            config.spot_light_material.clone_weak(),
+        )
+    }
+}
+
+fn camera_marker_visual(
+    config: &GizmoMarkerConfig,
+    billboard: bool,
+) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+    if billboard {
+        (
+            config.billboard_quad.clone_weak(),
+            config.camera_icon_material.clone_weak(),
+        )
+    } else {
+        (config.camera_mesh.clone_weak(), config.camera_material.clone_weak())
+    }
+}
+
 fn add_gizmo_markers(
     mut commands: Commands,
     gizmo_marker_meshes: Res<GizmoMarkerConfig>,
+    marker_style: Res<MarkerStyleConfig>,
 
     point_lights: GizmoMarkerQuery<PointLight>,
     directional_lights: GizmoMarkerQuery<DirectionalLight>,
+    spot_lights: GizmoMarkerQuery<SpotLight>,
     cameras: GizmoMarkerQuery<Camera, Without<EditorCamera>>,
 ) {
-    fn add<T: Component, F: QueryFilter, B: Bundle>(
+    let billboard = marker_style.0 == GizmoMarkerStyle::Billboard;
+
+    for entity in &point_lights {
+        commands.entity(entity).insert(HasGizmoMarker);
+        let (mesh, material) = point_light_marker_visual(&gizmo_marker_meshes, billboard);
+        spawn_gizmo_marker_child(
+            &mut commands,
+            entity,
+            "PointLight Gizmo",
+            mesh,
+            material,
+            billboard,
+        );
+    }
+
+    for entity in &directional_lights {
+        commands.entity(entity).insert(HasGizmoMarker);
+        let (mesh, material) = directional_light_marker_visual(&gizmo_marker_meshes, billboard);
+        spawn_gizmo_marker_child(
+            &mut commands,
+            entity,
+            "DirectionalLight Gizmo",
+            mesh,
+            material,
+            billboard,
+        );
+    }
+
+    for entity in &spot_lights {
+        commands.entity(entity).insert(HasGizmoMarker);
+        let (mesh, material) = spot_light_marker_visual(&gizmo_marker_meshes, billboard);
+        spawn_gizmo_marker_child(
+            &mut commands,
+            entity,
+            "SpotLight Gizmo",
+            mesh,
+            material,
+            billboard,
+        );
+    }
+
+    for entity in &cameras {
+        commands.entity(entity).insert((
+            HasGizmoMarker,
+            Visibility::Visible,
+            InheritedVisibility::VISIBLE,
+            ViewVisibility::default(),
+        ));
+        let (mesh, material) = camera_marker_visual(&gizmo_marker_meshes, billboard);
+        spawn_gizmo_marker_child(&mut commands, entity, "Camera Gizmo", mesh, material, billboard);
+    }
+}
+
+/// Despawns and respawns every existing marker child with the new
+/// [`GizmoMarkerStyle`] whenever [`MarkerStyleConfig`] changes. Without this,
+/// toggling the style in the UI only affects entities that gain a marker
+/// *after* the toggle, since [`add_gizmo_markers`] only ever looks at
+/// entities that don't have one yet.
+fn restyle_gizmo_markers(
+    mut commands: Commands,
+    gizmo_marker_meshes: Res<GizmoMarkerConfig>,
+    marker_style: Res<MarkerStyleConfig>,
+    children_query: Query<&Children>,
+    marker_children: Query<(), With<GizmoMarkerChild>>,
+    point_lights: Query<Entity, (With<PointLight>, With<HasGizmoMarker>)>,
+    directional_lights: Query<Entity, (With<DirectionalLight>, With<HasGizmoMarker>)>,
+    spot_lights: Query<Entity, (With<SpotLight>, With<HasGizmoMarker>)>,
+    cameras: Query<Entity, (With<Camera>, With<HasGizmoMarker>, Without<EditorCamera>)>,
+) {
+    if !marker_style.is_changed() {
+        return;
+    }
+
+    let billboard = marker_style.0 == GizmoMarkerStyle::Billboard;
+
+    fn despawn_marker_children(
         commands: &mut Commands,
-        query: GizmoMarkerQuery<T, F>,
-        name: &'static str,
-        f: impl Fn() -> B,
+        entity: Entity,
+        children_query: &Query<&Children>,
+        marker_children: &Query<(), With<GizmoMarkerChild>>,
     ) {
-        let render_layers = RenderLayers::layer(EDITOR_RENDER_LAYER);
-        for entity in &query {
-            commands
-                .entity(entity)
-                .insert(HasGizmoMarker)
-                .with_children(|commands| {
-                    commands.spawn((f(), render_layers, Name::new(name)));
-                });
+        let Ok(children) = children_query.get(entity) else {
+            return;
+        };
+        for &child in children {
+            if marker_children.contains(child) {
+                commands.entity(child).despawn_recursive();
+            }
         }
     }
 
-    add(&mut commands, point_lights, "PointLight Gizmo", || {
-        PbrBundle {
-            mesh: gizmo_marker_meshes.point_light_mesh.clone_weak(),
-            material: gizmo_marker_meshes.point_light_material.clone_weak(),
-            ..default()
-        }
-    });
-    add(
-        &mut commands,
-        directional_lights,
-        "DirectionalLight Gizmo",
-        || PbrBundle {
-            mesh: gizmo_marker_meshes.directional_light_mesh.clone_weak(),
-            material: gizmo_marker_meshes.directional_light_material.clone_weak(),
-            ..default()
-        },
-    );
+    for entity in &point_lights {
+        despawn_marker_children(&mut commands, entity, &children_query, &marker_children);
+        let (mesh, material) = point_light_marker_visual(&gizmo_marker_meshes, billboard);
+        spawn_gizmo_marker_child(
+            &mut commands,
+            entity,
+            "PointLight Gizmo",
+            mesh,
+            material,
+            billboard,
+        );
+    }
+
+    for entity in &directional_lights {
+        despawn_marker_children(&mut commands, entity, &children_query, &marker_children);
+        let (mesh, material) = directional_light_marker_visual(&gizmo_marker_meshes, billboard);
+        spawn_gizmo_marker_child(
+            &mut commands,
+            entity,
+            "DirectionalLight Gizmo",
+            mesh,
+            material,
+            billboard,
+        );
+    }
+
+    for entity in &spot_lights {
+        despawn_marker_children(&mut commands, entity, &children_query, &marker_children);
+        let (mesh, material) = spot_light_marker_visual(&gizmo_marker_meshes, billboard);
+        spawn_gizmo_marker_child(
+            &mut commands,
+            entity,
+            "SpotLight Gizmo",
+            mesh,
+            material,
+            billboard,
+        );
+    }
 
-    let render_layers = RenderLayers::layer(EDITOR_RENDER_LAYER);
     for entity in &cameras {
-        commands
-            .entity(entity)
-            .insert((
-                HasGizmoMarker,
-                Visibility::Visible,
-                InheritedVisibility::VISIBLE,
-                ViewVisibility::default(),
-            ))
-            .with_children(|commands| {
-                commands.spawn((
-                    PbrBundle {
-                        mesh: gizmo_marker_meshes.camera_mesh.clone_weak(),
-                        material: gizmo_marker_meshes.camera_material.clone_weak(),
-                        ..default()
-                    },
-                    render_layers,
-                    Name::new("Camera Gizmo"),
-                ));
-            });
+        despawn_marker_children(&mut commands, entity, &children_query, &marker_children);
+        let (mesh, material) = camera_marker_visual(&gizmo_marker_meshes, billboard);
+        spawn_gizmo_marker_child(&mut commands, entity, "Camera Gizmo", mesh, material, billboard);
+    }
+}
+
+/// Scale applied to billboard markers per metre of distance from the active
+/// editor camera (the 0.3-unit quad is 0.015 world units wide at 1 metre),
+/// which keeps their on-screen size constant for a fixed camera FOV.
+const BILLBOARD_REFERENCE_SIZE: f32 = 0.05;
+
+fn orient_billboard_gizmo_markers(
+    mut markers: Query<(&mut Transform, &GlobalTransform), With<BillboardGizmoMarker>>,
+    active_camera: Query<&GlobalTransform, With<ActiveEditorCamera>>,
+) {
+    let Ok(camera_transform) = active_camera.get_single() else {
+        return;
+    };
+    let camera_position = camera_transform.translation();
+
+    for (mut transform, global_transform) in &mut markers {
+        let marker_position = global_transform.translation();
+        let distance = camera_position.distance(marker_position).max(0.001);
+
+        let look_rotation = Transform::from_translation(marker_position)
+            .looking_at(camera_position, camera_transform.up())
+            .rotation;
+        let parent_rotation =
+            global_transform.compute_transform().rotation * transform.rotation.inverse();
+        // The child's global scale is the parent's scale times its own local
+        // scale, so divide the parent's scale back out before assigning the
+        // desired *world-space* size, otherwise a non-unit parent scale (on
+        // the light/camera entity or an ancestor) would make the billboard
+        // grow or shrink with it instead of staying constant-pixel-size.
+        let parent_scale = global_transform.compute_transform().scale / transform.scale;
+
+        transform.rotation = parent_rotation.inverse() * look_rotation;
+        transform.scale = Vec3::splat(distance * BILLBOARD_REFERENCE_SIZE) / parent_scale;
     }
 }
 fn convert_array_f32_to_f64<const N: usize>(a: &[f32; N]) -> [f64; N] {
@@ -193,6 +967,11 @@ fn collect_gizmo_config(
     ui: &mut egui::Ui,
     world: &mut World,
     gizmo_mode: transform_gizmo_egui::EnumSet<transform_gizmo_egui::GizmoMode>,
+    orientation: GizmoOrientation,
+    snapping: bool,
+    snap_angle: f32,
+    snap_distance: f32,
+    snap_scale: f32,
 ) -> Option<transform_gizmo_egui::GizmoConfig> {
     let (cam_transform, projection) = world
         .query_filtered::<(&GlobalTransform, &Projection), With<ActiveEditorCamera>>()
@@ -210,25 +989,48 @@ fn collect_gizmo_config(
     Some(transform_gizmo_egui::GizmoConfig {
         modes: gizmo_mode,
         viewport: ui.clip_rect(),
-        orientation: transform_gizmo_egui::GizmoOrientation::Local,
+        orientation,
         view_matrix: transform_view_matrix.into(),
         projection_matrix: transform_projection_matrix.into(),
+        snapping,
+        snap_angle,
+        snap_distance,
+        snap_scale,
         ..Default::default()
     })
 }
+
+/// Where the gizmo is placed, and how its drag is distributed, when more
+/// than one entity is selected.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum PivotMode {
+    /// Gizmo sits at the centroid of the selected translations; the drag
+    /// delta is composed onto every selected entity about that shared pivot.
+    MedianPoint,
+    /// Gizmo sits at the last-selected entity; same shared-delta behavior
+    /// as `MedianPoint`, just anchored differently.
+    ActiveElement,
+    /// Every selected entity gets its own gizmo interaction (current/default
+    /// behavior): each pivots about itself.
+    #[default]
+    IndividualOrigins,
+}
+
 fn draw_gizmo(
     ui: &mut egui::Ui,
     world: &mut World,
     selected_entities: &[Entity],
     gizmo: &mut transform_gizmo_egui::Gizmo,
+    pivot_mode: PivotMode,
+    active_entity: Option<Entity>,
 ) {
     let all_transform_and_entity = selected_entities.iter().filter_map(|selected| {
         let Some(global_transform) = world.get::<GlobalTransform>(*selected) else {
             return None;
         };
-    
+
         let (scale, rotation, translation) = global_transform.to_scale_rotation_translation();
-    
+
         let gizmo_transform = transform_gizmo_egui::math::Transform::from_scale_rotation_translation(
             transform_gizmo_egui::mint::Vector3::from_slice(&convert_array_f32_to_f64(
                 &scale.to_array(),
@@ -243,11 +1045,42 @@ fn draw_gizmo(
         Some((gizmo_transform, *selected))
     }).collect::<Vec<_>>();
 
+    if all_transform_and_entity.is_empty() {
+        finish_transform_gizmo_drag(world);
+        return;
+    }
+
+    if pivot_mode == PivotMode::IndividualOrigins || all_transform_and_entity.len() == 1 {
+        draw_gizmo_individual_origins(ui, world, &all_transform_and_entity, gizmo);
+    } else {
+        draw_gizmo_shared_pivot(
+            ui,
+            world,
+            &all_transform_and_entity,
+            gizmo,
+            pivot_mode,
+            active_entity,
+        );
+    }
+}
+
+fn draw_gizmo_individual_origins(
+    ui: &mut egui::Ui,
+    world: &mut World,
+    all_transform_and_entity: &[(transform_gizmo_egui::math::Transform, Entity)],
+    gizmo: &mut transform_gizmo_egui::Gizmo,
+) {
     let all_transform = all_transform_and_entity.iter().map(|(transform,_)|transform.clone()).collect::<Vec<_>>();
     let Some((_, transforms)) = gizmo.interact(ui,& all_transform) else {
+        // Not currently dragging: if we were tracking a drag up to the
+        // previous frame, this is the frame it ended on.
+        finish_transform_gizmo_drag(world);
         return;
     };
 
+    begin_transform_gizmo_drag(world, all_transform_and_entity);
+
+    let mut latest = Vec::with_capacity(all_transform_and_entity.len());
     for ((_,entity), result) in all_transform_and_entity.iter().zip(transforms.iter()) {
         let global_affine = world.get::<GlobalTransform>(*entity).unwrap().affine();
         let mut transform = world.get_mut::<Transform>(*entity).unwrap();
@@ -273,6 +1106,276 @@ fn draw_gizmo(
             ),
         };
         *transform = (inverse_parent_transform * global_transform).into();
+        latest.push((*entity, GlobalTransform::from(global_transform)));
+    }
+
+    if let Some(drag) = world.resource_mut::<GizmoDragTracker>().0.as_mut() {
+        drag.latest = latest;
+    }
+}
+
+/// Places a single gizmo at the selection's median point or active element,
+/// then composes the resulting delta onto every selected entity's
+/// `GlobalTransform`, converting back through its own parent affine
+/// (same round-trip as [`draw_gizmo_individual_origins`]).
+fn draw_gizmo_shared_pivot(
+    ui: &mut egui::Ui,
+    world: &mut World,
+    all_transform_and_entity: &[(transform_gizmo_egui::math::Transform, Entity)],
+    gizmo: &mut transform_gizmo_egui::Gizmo,
+    pivot_mode: PivotMode,
+    active_entity: Option<Entity>,
+) {
+    // Fall back to the last entry if the tracked active entity isn't part of
+    // this selection (e.g. it was despawned, or no frame has diffed the
+    // selection yet) so a pivot is always produced.
+    let active = active_entity
+        .and_then(|entity| {
+            all_transform_and_entity
+                .iter()
+                .find(|(_, candidate)| *candidate == entity)
+        })
+        .unwrap_or_else(|| all_transform_and_entity.last().unwrap());
+
+    // `GizmoOrientation::Local` derives the gizmo's axes from the rotation of
+    // the transform fed into `interact`, so the pivot must carry a real
+    // rotation rather than identity or `Local`/`Global` silently collapse
+    // into the same behavior for any shared-pivot multi-selection. Both modes
+    // use the active entity's rotation, mirroring Blender's "active element"
+    // orientation for median-point transforms.
+    let pivot_rotation = active.0.rotation;
+
+    let pivot_translation = match pivot_mode {
+        PivotMode::ActiveElement => active.0.translation,
+        PivotMode::MedianPoint => {
+            let count = all_transform_and_entity.len() as f64;
+            let sum = all_transform_and_entity.iter().fold([0.0; 3], |mut acc, (t, _)| {
+                acc[0] += t.translation.x;
+                acc[1] += t.translation.y;
+                acc[2] += t.translation.z;
+                acc
+            });
+            transform_gizmo_egui::mint::Vector3::from_slice(&[
+                sum[0] / count,
+                sum[1] / count,
+                sum[2] / count,
+            ])
+        }
+        PivotMode::IndividualOrigins => unreachable!(
+            "draw_gizmo only calls draw_gizmo_shared_pivot for MedianPoint/ActiveElement"
+        ),
+    };
+
+    let pivot_transform = transform_gizmo_egui::math::Transform::from_scale_rotation_translation(
+        transform_gizmo_egui::mint::Vector3::from_slice(&[1.0, 1.0, 1.0]),
+        pivot_rotation,
+        pivot_translation,
+    );
+
+    let Some((_, results)) = gizmo.interact(ui, &[pivot_transform.clone()]) else {
+        finish_transform_gizmo_drag(world);
+        return;
+    };
+    let result = results[0].clone();
+
+    begin_transform_gizmo_drag(world, all_transform_and_entity);
+
+    let delta_affine =
+        gizmo_math_transform_to_affine(&result) * gizmo_math_transform_to_affine(&pivot_transform).inverse();
+
+    let mut latest = Vec::with_capacity(all_transform_and_entity.len());
+    for (_, entity) in all_transform_and_entity {
+        let global_affine = world.get::<GlobalTransform>(*entity).unwrap().affine();
+        let target = GlobalTransform::from(delta_affine * global_affine);
+        apply_global_transform(world, *entity, target);
+        latest.push((*entity, target));
+    }
+
+    if let Some(drag) = world.resource_mut::<GizmoDragTracker>().0.as_mut() {
+        drag.latest = latest;
+    }
+}
+
+fn gizmo_math_transform_to_affine(t: &transform_gizmo_egui::math::Transform) -> Affine3A {
+    let scale = Vec3::new(t.scale.x as f32, t.scale.y as f32, t.scale.z as f32);
+    let rotation = Quat::from_xyzw(
+        t.rotation.v.x as f32,
+        t.rotation.v.y as f32,
+        t.rotation.v.z as f32,
+        t.rotation.s as f32,
+    );
+    let translation = Vec3::new(
+        t.translation.x as f32,
+        t.translation.y as f32,
+        t.translation.z as f32,
+    );
+    Affine3A::from_scale_rotation_translation(scale, rotation, translation)
+}
+
+/// One drag, from the moment `gizmo.interact` first returns `Some` until it
+/// returns `None` again. Multi-entity selections are grouped under a single
+/// tracker so the whole drag becomes one undo step.
+#[derive(Default)]
+struct ActiveGizmoDrag {
+    start: Vec<(Entity, GlobalTransform)>,
+    latest: Vec<(Entity, GlobalTransform)>,
+}
+
+#[derive(Resource, Default)]
+struct GizmoDragTracker(Option<ActiveGizmoDrag>);
+
+fn begin_transform_gizmo_drag(
+    world: &mut World,
+    all_transform_and_entity: &[(transform_gizmo_egui::math::Transform, Entity)],
+) {
+    if world.resource::<GizmoDragTracker>().0.is_some() {
+        return;
+    }
+
+    let start = all_transform_and_entity
+        .iter()
+        .filter_map(|(_, entity)| {
+            world
+                .get::<GlobalTransform>(*entity)
+                .map(|global_transform| (*entity, *global_transform))
+        })
+        .collect();
+
+    world.resource_mut::<GizmoDragTracker>().0 = Some(ActiveGizmoDrag {
+        start,
+        latest: Vec::new(),
+    });
+}
+
+fn finish_transform_gizmo_drag(world: &mut World) {
+    let Some(drag) = world.resource_mut::<GizmoDragTracker>().0.take() else {
+        return;
+    };
+
+    let changes = drag
+        .start
+        .iter()
+        .filter_map(|(entity, from)| {
+            let (_, to) = drag.latest.iter().find(|(e, _)| e == entity)?;
+            (from != to).then_some((*entity, *from, *to))
+        })
+        .collect::<Vec<_>>();
+
+    if changes.is_empty() {
+        return;
+    }
+
+    for (entity, from, to) in &changes {
+        world.send_event(TransformGizmoEvent {
+            entity: *entity,
+            from: *from,
+            to: *to,
+        });
+    }
+
+    world
+        .resource_mut::<UndoRedoStack>()
+        .push(TransformUndoStep { changes });
+}
+
+/// Emitted when a gizmo drag completes, once for every entity whose transform
+/// the drag changed, mirroring the event model used by `bevy_transform_gizmo`.
+#[derive(Event)]
+pub struct TransformGizmoEvent {
+    pub entity: Entity,
+    pub from: GlobalTransform,
+    pub to: GlobalTransform,
+}
+
+const TRANSFORM_UNDO_HISTORY_CAPACITY: usize = 100;
+
+#[derive(Clone)]
+struct TransformUndoStep {
+    changes: Vec<(Entity, GlobalTransform, GlobalTransform)>,
+}
+
+/// Bounded history of applied gizmo drags with a cursor splitting applied
+/// entries (`..cursor`) from the ones available to redo (`cursor..`).
+#[derive(Resource, Default)]
+struct UndoRedoStack {
+    entries: VecDeque<TransformUndoStep>,
+    cursor: usize,
+}
+
+impl UndoRedoStack {
+    fn push(&mut self, step: TransformUndoStep) {
+        self.entries.truncate(self.cursor);
+        self.entries.push_back(step);
+        self.cursor += 1;
+
+        if self.entries.len() > TRANSFORM_UNDO_HISTORY_CAPACITY {
+            self.entries.pop_front();
+            self.cursor -= 1;
+        }
+    }
+
+    fn undo(&mut self) -> Option<TransformUndoStep> {
+        if self.cursor == 0 {
+            return None;
+        }
+        self.cursor -= 1;
+        self.entries.get(self.cursor).cloned()
+    }
+
+    fn redo(&mut self) -> Option<TransformUndoStep> {
+        let step = self.entries.get(self.cursor).cloned()?;
+        self.cursor += 1;
+        Some(step)
+    }
+}
+
+fn apply_global_transform(world: &mut World, entity: Entity, target: GlobalTransform) {
+    let Some(global_affine) = world.get::<GlobalTransform>(entity).map(|gt| gt.affine()) else {
+        return;
+    };
+    let Some(mut transform) = world.get_mut::<Transform>(entity) else {
+        return;
+    };
+    let parent_affine = global_affine * transform.compute_affine().inverse();
+    let inverse_parent_transform = GlobalTransform::from(parent_affine.inverse());
+    *transform = (inverse_parent_transform * target).into();
+}
+
+fn transform_undo_redo_input(
+    world: &mut World,
+    egui_contexts_state: &mut SystemState<EguiContexts>,
+) {
+    // The Gizmos window's own `DragValue` fields (and any other editor text
+    // field, e.g. renaming an entity) have their own Ctrl+Z text-edit undo;
+    // without this guard, pressing Ctrl+Z to fix a typo in one of them would
+    // *also* pop a scene-transform undo step behind the user's back.
+    let wants_keyboard_input = egui_contexts_state
+        .get_mut(world)
+        .ctx_mut()
+        .wants_keyboard_input();
+    if wants_keyboard_input {
+        return;
     }
 
+    let keyboard = world.resource::<ButtonInput<KeyCode>>();
+    let ctrl = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
+    let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+    let just_pressed_z = keyboard.just_pressed(KeyCode::KeyZ);
+
+    if !ctrl || !just_pressed_z {
+        return;
+    }
+
+    let step = if shift {
+        world.resource_mut::<UndoRedoStack>().redo()
+    } else {
+        world.resource_mut::<UndoRedoStack>().undo()
+    };
+    let Some(step) = step else {
+        return;
+    };
+
+    for (entity, from, to) in step.changes {
+        apply_global_transform(world, entity, if shift { to } else { from });
+    }
 }